@@ -0,0 +1,480 @@
+//! The heap-backed `CircularBuffer`, kept behind the `std` feature since it
+//! relies on `Vec` for its backing storage. See [`static_buffer`] for an
+//! inline, allocation-free variant usable under `#![no_std]`.
+//!
+//! [`static_buffer`]: crate::static_buffer
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+
+/// Represents errors that can occur when interacting with the CircularBuffer.
+#[derive(Debug, PartialEq)]
+pub enum BufferError {
+    /// Error returned when attempting to push to a full buffer.
+    BufferFull,
+}
+
+impl fmt::Display for BufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferFull => write!(f, "circular buffer: cannot push, buffer is full"),
+        }
+    }
+}
+
+impl std::error::Error for BufferError {}
+
+/// # Safety
+/// Every index in `slice` must be initialized.
+unsafe fn slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    &*(slice as *const [MaybeUninit<T>] as *const [T])
+}
+
+/// # Safety
+/// Every index in `slice` must be initialized.
+unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+}
+
+pub struct CircularBuffer<T> {
+    buffer: Vec<MaybeUninit<T>>,
+    front: usize,
+    rear: usize,
+    capacity: usize,
+    len: usize,
+}
+
+impl<T> CircularBuffer<T> {
+    /// Creates a new CircularBuffer with the specified capacity.
+    pub fn new(size: usize) -> Self {
+        let mut buffer = Vec::with_capacity(size);
+        for _ in 0..size {
+            buffer.push(MaybeUninit::uninit());
+        }
+        CircularBuffer {
+            buffer,
+            front: 0,
+            rear: 0,
+            capacity: size,
+            len: 0,
+        }
+    }
+
+    /// Adds an element to the rear of the buffer.
+    /// Returns `Ok(())` if successful or `Err(BufferError::BufferFull)` if the buffer is full.
+    pub fn push(&mut self, value: T) -> Result<(), BufferError> {
+        if self.is_full() {
+            return Err(BufferError::BufferFull);
+        }
+
+        self.buffer[self.rear] = MaybeUninit::new(value);
+        self.rear = (self.rear + 1) % self.capacity;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element from the front of the buffer.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = std::mem::replace(&mut self.buffer[self.front], MaybeUninit::uninit());
+        // SAFETY: `front` is within `0..len`, so it holds an initialized value.
+        let value = unsafe { slot.assume_init() };
+        self.front = (self.front + 1) % self.capacity;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks if the buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Retrieves a reference to the element at the given index.
+    /// Returns `Some(&T)` if the index is within bounds or `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let absolute_index = (self.front + index) % self.capacity;
+        // SAFETY: `absolute_index` falls within the live `0..len` range.
+        Some(unsafe { self.buffer[absolute_index].assume_init_ref() })
+    }
+
+    /// Adds an element to the rear of the buffer, overwriting the oldest
+    /// element if the buffer is full.
+    ///
+    /// Returns `Some(old)` with the element that was evicted from `front`
+    /// when the buffer was full, or `None` if there was room and `value`
+    /// was simply appended, matching the behavior of [`push`](Self::push).
+    /// A zero-capacity buffer drops `value` and returns `None`.
+    pub fn push_overwrite(&mut self, value: T) -> Option<T> {
+        if self.capacity == 0 {
+            // A zero-capacity buffer can never hold anything, so there's
+            // nothing to evict; drop `value` and report no eviction.
+            return None;
+        }
+
+        if !self.is_full() {
+            self.push(value).expect("buffer has room, push cannot fail");
+            return None;
+        }
+
+        let slot = std::mem::replace(&mut self.buffer[self.front], MaybeUninit::uninit());
+        // SAFETY: `front` is within `0..len`, so it holds an initialized value.
+        let evicted = unsafe { slot.assume_init() };
+        self.buffer[self.rear] = MaybeUninit::new(value);
+        self.front = (self.front + 1) % self.capacity;
+        self.rear = (self.rear + 1) % self.capacity;
+        Some(evicted)
+    }
+
+    /// Adds an element to the front of the buffer.
+    /// Returns `Ok(())` if successful or `Err(BufferError::BufferFull)` if the buffer is full.
+    pub fn push_front(&mut self, value: T) -> Result<(), BufferError> {
+        if self.is_full() {
+            return Err(BufferError::BufferFull);
+        }
+
+        self.front = (self.front + self.capacity - 1) % self.capacity;
+        self.buffer[self.front] = MaybeUninit::new(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element from the rear of the buffer.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.rear = (self.rear + self.capacity - 1) % self.capacity;
+        let slot = std::mem::replace(&mut self.buffer[self.rear], MaybeUninit::uninit());
+        // SAFETY: `rear - 1` is within `0..len`, so it holds an initialized value.
+        let value = unsafe { slot.assume_init() };
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes multiple elements from the front of the buffer.
+    pub fn remove_multiple(&mut self, count: usize) {
+        let count_to_remove = if count > self.len { self.len } else { count };
+
+        for _ in 0..count_to_remove {
+            let mut slot = std::mem::replace(&mut self.buffer[self.front], MaybeUninit::uninit());
+            // SAFETY: `front` is within `0..len`, so it holds an initialized value.
+            unsafe { slot.assume_init_drop() };
+            self.front = (self.front + 1) % self.capacity;
+            self.len -= 1;
+        }
+    }
+
+    /// Returns the live elements as two contiguous slices in logical order.
+    ///
+    /// The first slice covers `front..min(front + len, capacity)`; the
+    /// second covers the wrapped remainder and is empty unless the buffer
+    /// has wrapped around the end of its backing storage. Concatenating
+    /// the two slices yields the same order as [`iter`](Self::iter).
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.is_empty() {
+            return (&[], &[]);
+        }
+
+        let end = self.front + self.len;
+        if end <= self.capacity {
+            // SAFETY: `front..end` is entirely within the live range.
+            let live = unsafe { slice_assume_init_ref(&self.buffer[self.front..end]) };
+            (live, &[])
+        } else {
+            // SAFETY: both ranges are entirely within the live range.
+            let first = unsafe { slice_assume_init_ref(&self.buffer[self.front..self.capacity]) };
+            let second = unsafe { slice_assume_init_ref(&self.buffer[0..end % self.capacity]) };
+            (first, second)
+        }
+    }
+
+    /// Mutable counterpart to [`as_slices`](Self::as_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        if self.is_empty() {
+            return (&mut [], &mut []);
+        }
+
+        let end = self.front + self.len;
+        if end <= self.capacity {
+            // SAFETY: `front..end` is entirely within the live range.
+            let live = unsafe { slice_assume_init_mut(&mut self.buffer[self.front..end]) };
+            (live, &mut [])
+        } else {
+            let (head, tail) = self.buffer.split_at_mut(self.front);
+            // SAFETY: both ranges are entirely within the live range.
+            let first = unsafe { slice_assume_init_mut(tail) };
+            let second = unsafe { slice_assume_init_mut(&mut head[..end % self.capacity]) };
+            (first, second)
+        }
+    }
+
+    /// Returns an iterator over `&T` in logical front-to-rear order.
+    ///
+    /// The iterator implements [`DoubleEndedIterator`], so `.rev()` walks
+    /// the buffer newest-to-oldest without consuming it.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buffer: &self.buffer,
+            front: self.front,
+            capacity: self.capacity,
+            next_front: 0,
+            remaining: self.len,
+        }
+    }
+
+    /// Mutable counterpart to [`iter`](Self::iter), yielding `&mut T`.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            buffer: self.buffer.as_mut_ptr(),
+            front: self.front,
+            capacity: self.capacity,
+            next_front: 0,
+            remaining: self.len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for CircularBuffer<T> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            let index = (self.front + i) % self.capacity;
+            // SAFETY: every index in `0..len` starting at `front` is initialized.
+            unsafe { self.buffer[index].assume_init_drop() };
+        }
+    }
+}
+
+impl<T: Clone> Clone for CircularBuffer<T> {
+    fn clone(&self) -> Self {
+        let mut buffer = Vec::with_capacity(self.capacity);
+        for _ in 0..self.capacity {
+            buffer.push(MaybeUninit::uninit());
+        }
+
+        for i in 0..self.len {
+            let index = (self.front + i) % self.capacity;
+            // SAFETY: every index in `0..len` starting at `front` is initialized.
+            let value = unsafe { self.buffer[index].assume_init_ref() }.clone();
+            buffer[index] = MaybeUninit::new(value);
+        }
+
+        CircularBuffer {
+            buffer,
+            front: self.front,
+            rear: self.rear,
+            capacity: self.capacity,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, second) = self.as_slices();
+        f.debug_struct("CircularBuffer")
+            .field("capacity", &self.capacity)
+            .field("elements", &first.iter().chain(second.iter()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T> Index<usize> for CircularBuffer<T> {
+    type Output = T;
+
+    /// Panics if `index` is out of bounds, matching `Vec`/slice indexing.
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for CircularBuffer<T> {
+    /// Panics if `index` is out of bounds, matching `Vec`/slice indexing.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len {
+            panic!("index out of bounds");
+        }
+
+        let absolute_index = (self.front + index) % self.capacity;
+        // SAFETY: `absolute_index` falls within the live `0..len` range.
+        unsafe { self.buffer[absolute_index].assume_init_mut() }
+    }
+}
+
+/// Iterator that consumes the CircularBuffer and yields its elements in order.
+pub struct CircularBufferIntoIter<T> {
+    buffer: Vec<MaybeUninit<T>>,
+    front: usize,
+    capacity: usize,
+    current: usize,
+    remaining: usize,
+}
+
+impl<T> Iterator for CircularBufferIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = (self.front + self.current) % self.capacity;
+        self.current += 1;
+        self.remaining -= 1;
+
+        let slot = std::mem::replace(&mut self.buffer[index], MaybeUninit::uninit());
+        // SAFETY: `index` falls within the live range handed off by `CircularBuffer`.
+        Some(unsafe { slot.assume_init() })
+    }
+}
+
+impl<T> Drop for CircularBufferIntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<T> IntoIterator for CircularBuffer<T> {
+    type Item = T;
+    type IntoIter = CircularBufferIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // `CircularBuffer` has a `Drop` impl, so its fields can't be moved
+        // out directly; go through `ManuallyDrop` to take ownership of
+        // `buffer` without also running `self`'s destructor on it.
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again and is never dropped, so
+        // `buffer` is read out exactly once.
+        let buffer = unsafe { std::ptr::read(&this.buffer) };
+
+        CircularBufferIntoIter {
+            buffer,
+            front: this.front,
+            capacity: this.capacity,
+            current: 0,
+            remaining: this.len,
+        }
+    }
+}
+
+/// Non-consuming iterator over `&T`, yielded by [`CircularBuffer::iter`].
+pub struct Iter<'a, T> {
+    buffer: &'a [MaybeUninit<T>],
+    front: usize,
+    capacity: usize,
+    next_front: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = (self.front + self.next_front) % self.capacity;
+        self.next_front += 1;
+        self.remaining -= 1;
+
+        // SAFETY: `index` falls within the live range handed off by `CircularBuffer`.
+        Some(unsafe { self.buffer[index].assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let offset = self.next_front + self.remaining;
+        let index = (self.front + offset) % self.capacity;
+
+        // SAFETY: `index` falls within the live range handed off by `CircularBuffer`.
+        Some(unsafe { self.buffer[index].assume_init_ref() })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Non-consuming mutable iterator over `&mut T`, yielded by
+/// [`CircularBuffer::iter_mut`].
+pub struct IterMut<'a, T> {
+    buffer: *mut MaybeUninit<T>,
+    front: usize,
+    capacity: usize,
+    next_front: usize,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = (self.front + self.next_front) % self.capacity;
+        self.next_front += 1;
+        self.remaining -= 1;
+
+        // SAFETY: each call yields a distinct `index`, so the `&mut T`
+        // references handed out across the iterator's lifetime never alias.
+        Some(unsafe { (*self.buffer.add(index)).assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let offset = self.next_front + self.remaining;
+        let index = (self.front + offset) % self.capacity;
+
+        // SAFETY: each call yields a distinct `index`, so the `&mut T`
+        // references handed out across the iterator's lifetime never alias.
+        Some(unsafe { (*self.buffer.add(index)).assume_init_mut() })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+// SAFETY: `IterMut` only ever dereferences its raw pointer to produce
+// `&mut T`s that behave exactly like the `PhantomData<&'a mut T>` marker
+// promises, so it inherits `T`'s `Send` requirements like a normal `&mut T`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
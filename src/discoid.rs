@@ -2,6 +2,7 @@ pub struct DiscoidBuffer<T> {
     buffer: Vec<Option<T>>,
     front: usize,
     capacity: usize,
+    discarded_base: u64,
 }
 
 /// A circular buffer that efficiently handles elements of small and non-complex types.
@@ -40,10 +41,19 @@ impl<T> DiscoidBuffer<T> {
             buffer: (0..size).map(|_| None).collect(),
             front: 0,
             capacity: size,
+            discarded_base: 0,
         }
     }
 
-    pub fn set_at_index(&mut self, index: usize, value: T) {
+    /// Sets the element at the given relative index and returns a stable
+    /// `u64` handle for it.
+    ///
+    /// Unlike `index`, which is reinterpreted every time `discard_front`
+    /// slides the window, the returned handle keeps referring to this same
+    /// element (via [`get_by_handle`](Self::get_by_handle) /
+    /// [`get_by_handle_mut`](Self::get_by_handle_mut)) until it is
+    /// discarded, regardless of how the window moves afterwards.
+    pub fn set_at_index(&mut self, index: usize, value: T) -> u64 {
         let buffer_len = self.capacity;
         if index >= buffer_len {
             panic!("discoid buffer: index out of bounds");
@@ -51,6 +61,7 @@ impl<T> DiscoidBuffer<T> {
 
         let absolute_index = (self.front + index) % buffer_len;
         self.buffer[absolute_index] = Some(value);
+        self.discarded_base + index as u64
     }
 
     pub fn get_ref_at_index(&self, index: usize) -> Option<&T> {
@@ -72,6 +83,32 @@ impl<T> DiscoidBuffer<T> {
             self.buffer[self.front] = None;
             self.front = (self.front + 1) % self.capacity;
         }
+        self.discarded_base += count as u64;
+    }
+
+    /// Resolves a handle previously returned by [`set_at_index`](Self::set_at_index)
+    /// to its current physical slot.
+    ///
+    /// Returns `None` if the element that handle referred to has already
+    /// been discarded (or the handle was never valid), rather than
+    /// silently returning whatever now occupies that slot.
+    pub fn get_by_handle(&self, token: u64) -> Option<&T> {
+        let index = token.checked_sub(self.discarded_base)?;
+        if index >= self.capacity as u64 {
+            return None;
+        }
+        self.get_ref_at_index(index as usize)
+    }
+
+    /// Mutable counterpart to [`get_by_handle`](Self::get_by_handle).
+    pub fn get_by_handle_mut(&mut self, token: u64) -> Option<&mut T> {
+        let index = token.checked_sub(self.discarded_base)?;
+        if index >= self.capacity as u64 {
+            return None;
+        }
+
+        let absolute_index = (self.front + index as usize) % self.capacity;
+        self.buffer[absolute_index].as_mut()
     }
 
     pub fn get_bits_representation(&self) -> u64 {
@@ -141,4 +178,57 @@ mod discoid_tests {
 
         assert_eq!(discoid_buffer.get_ref_at_index(7), None);
     }
+
+    #[test]
+    fn handle_survives_discard_front() {
+        let mut discoid_buffer = DiscoidBuffer::<i32>::new(4);
+
+        let handle_a = discoid_buffer.set_at_index(0, 10);
+        let handle_b = discoid_buffer.set_at_index(1, 20);
+
+        assert_eq!(discoid_buffer.get_by_handle(handle_a), Some(&10));
+        assert_eq!(discoid_buffer.get_by_handle(handle_b), Some(&20));
+
+        discoid_buffer.discard_front(1);
+
+        // The window has slid, so the relative index for `b` changed, but
+        // its handle still resolves to the same element.
+        assert_eq!(discoid_buffer.get_by_handle(handle_b), Some(&20));
+        assert_eq!(discoid_buffer.get_ref_at_index(0), Some(&20));
+    }
+
+    #[test]
+    fn handle_becomes_none_once_discarded() {
+        let mut discoid_buffer = DiscoidBuffer::<i32>::new(4);
+
+        let handle_a = discoid_buffer.set_at_index(0, 10);
+        discoid_buffer.discard_front(1);
+
+        assert_eq!(discoid_buffer.get_by_handle(handle_a), None);
+    }
+
+    #[test]
+    fn handle_mut_allows_in_place_update() {
+        let mut discoid_buffer = DiscoidBuffer::<i32>::new(4);
+
+        let handle = discoid_buffer.set_at_index(2, 10);
+        if let Some(value) = discoid_buffer.get_by_handle_mut(handle) {
+            *value += 5;
+        }
+
+        assert_eq!(discoid_buffer.get_by_handle(handle), Some(&15));
+    }
+
+    #[test]
+    fn handles_keep_increasing_as_window_slides() {
+        let mut discoid_buffer = DiscoidBuffer::<i32>::new(4);
+
+        let handle_a = discoid_buffer.set_at_index(0, 1);
+        discoid_buffer.discard_front(2);
+        let handle_b = discoid_buffer.set_at_index(0, 2);
+
+        assert!(handle_b > handle_a);
+        assert_eq!(discoid_buffer.get_by_handle(handle_a), None);
+        assert_eq!(discoid_buffer.get_by_handle(handle_b), Some(&2));
+    }
 }
@@ -0,0 +1,120 @@
+//! A lock-free single-producer/single-consumer channel built on the same
+//! ring-buffer discipline as [`CircularBuffer`](crate::CircularBuffer), for
+//! passing values between threads (e.g. audio/real-time pipelines) without a
+//! mutex.
+
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct Shared<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    slots: usize,
+    front: AtomicUsize,
+    rear: AtomicUsize,
+}
+
+// SAFETY: cells are only ever accessed by whichever side (writer or
+// reader) currently owns the slot, as established by the `front`/`rear`
+// handoff below, so `Shared<T>` can be shared across threads whenever `T`
+// itself is safe to send between them.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let front = *self.front.get_mut();
+        let rear = *self.rear.get_mut();
+        let mut index = front;
+        while index != rear {
+            // SAFETY: every slot in `front..rear` was written by the
+            // writer and never consumed by the reader.
+            unsafe { (*self.buffer[index].get()).assume_init_drop() };
+            index = (index + 1) % self.slots;
+        }
+    }
+}
+
+/// The producer half of a [`bounded`] channel.
+pub struct Writer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The consumer half of a [`bounded`] channel.
+pub struct Reader<T> {
+    shared: Arc<Shared<T>>,
+}
+
+// SAFETY: `Writer` only ever touches the slot it owns via `rear`.
+unsafe impl<T: Send> Send for Writer<T> {}
+// SAFETY: `Reader` only ever touches the slot it owns via `front`.
+unsafe impl<T: Send> Send for Reader<T> {}
+
+/// Creates a bounded SPSC channel, returning its `Writer` and `Reader`
+/// halves. The channel holds at most `capacity` elements; one extra slot
+/// is reserved internally to disambiguate the full and empty states.
+pub fn bounded<T>(capacity: usize) -> (Writer<T>, Reader<T>) {
+    let slots = capacity + 1;
+    let mut buffer = Vec::with_capacity(slots);
+    for _ in 0..slots {
+        buffer.push(UnsafeCell::new(MaybeUninit::uninit()));
+    }
+
+    let shared = Arc::new(Shared {
+        buffer: buffer.into_boxed_slice(),
+        slots,
+        front: AtomicUsize::new(0),
+        rear: AtomicUsize::new(0),
+    });
+
+    (
+        Writer {
+            shared: Arc::clone(&shared),
+        },
+        Reader { shared },
+    )
+}
+
+impl<T> Writer<T> {
+    /// Pushes a value onto the channel.
+    ///
+    /// Returns `Err(value)` handing the value back if the channel is
+    /// currently full (the reader hasn't kept up).
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let shared = &*self.shared;
+        let rear = shared.rear.load(Ordering::Relaxed);
+        let next_rear = (rear + 1) % shared.slots;
+        if next_rear == shared.front.load(Ordering::Acquire) {
+            return Err(value);
+        }
+
+        // SAFETY: the writer exclusively owns slot `rear` until it
+        // publishes the new `rear` below, since the reader only ever
+        // touches slots strictly before the last-published `rear`.
+        unsafe {
+            (*shared.buffer[rear].get()).write(value);
+        }
+        shared.rear.store(next_rear, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<T> Reader<T> {
+    /// Pops the oldest value off the channel, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let shared = &*self.shared;
+        let front = shared.front.load(Ordering::Relaxed);
+        if front == shared.rear.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // SAFETY: `front != rear`, so slot `front` was published by the
+        // writer and the reader exclusively owns it until it advances
+        // `front` below.
+        let value = unsafe { (*shared.buffer[front].get()).assume_init_read() };
+        shared
+            .front
+            .store((front + 1) % shared.slots, Ordering::Release);
+        Some(value)
+    }
+}
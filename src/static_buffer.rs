@@ -0,0 +1,123 @@
+//! A fixed-capacity circular buffer with inline storage, usable under
+//! `#![no_std]` and other allocation-free contexts such as embedded or
+//! interrupt handlers.
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// Represents errors that can occur when interacting with the
+/// [`StaticCircularBuffer`].
+#[derive(Debug, PartialEq)]
+pub enum StaticBufferError {
+    /// Error returned when attempting to push to a full buffer.
+    BufferFull,
+}
+
+impl fmt::Display for StaticBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferFull => write!(f, "static circular buffer: cannot push, buffer is full"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaticBufferError {}
+
+/// A circular buffer with inline, stack-allocated storage for exactly `N`
+/// elements.
+///
+/// Unlike [`CircularBuffer`](crate::CircularBuffer), which heap-allocates
+/// its backing `Vec`, a `StaticCircularBuffer` performs no allocation and
+/// has a capacity fixed at compile time, making it usable under
+/// `#![no_std]` and in interrupt/embedded contexts.
+pub struct StaticCircularBuffer<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    front: usize,
+    rear: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> StaticCircularBuffer<T, N> {
+    /// Creates a new, empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            // SAFETY: an array of `MaybeUninit<T>` does not itself need to
+            // be initialized, only the `T`s it may later hold.
+            buffer: unsafe { MaybeUninit::uninit().assume_init() },
+            front: 0,
+            rear: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds an element to the rear of the buffer.
+    /// Returns `Ok(())` if successful or `Err(StaticBufferError::BufferFull)` if the buffer is full.
+    pub fn push(&mut self, value: T) -> Result<(), StaticBufferError> {
+        if self.is_full() {
+            return Err(StaticBufferError::BufferFull);
+        }
+
+        self.buffer[self.rear] = MaybeUninit::new(value);
+        self.rear = (self.rear + 1) % N;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element from the front of the buffer.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = core::mem::replace(&mut self.buffer[self.front], MaybeUninit::uninit());
+        // SAFETY: `front` is within `0..len`, so it holds an initialized value.
+        let value = unsafe { slot.assume_init() };
+        self.front = (self.front + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks if the buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Retrieves a reference to the element at the given index.
+    /// Returns `Some(&T)` if the index is within bounds or `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let absolute_index = (self.front + index) % N;
+        // SAFETY: `absolute_index` falls within the live `0..len` range.
+        Some(unsafe { self.buffer[absolute_index].assume_init_ref() })
+    }
+}
+
+impl<T, const N: usize> Default for StaticCircularBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for StaticCircularBuffer<T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            let index = (self.front + i) % N;
+            // SAFETY: every index in `0..len` starting at `front` is initialized.
+            unsafe { self.buffer[index].assume_init_drop() };
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use discoid::BufferError;
 use discoid::CircularBuffer;
 
@@ -217,6 +219,259 @@ fn test_full_capacity_storage() {
     assert_eq!(collected, vec![0, 1, 2, 3, 4]);
 }
 
+#[test]
+fn test_push_overwrite_with_room() {
+    let mut cb = CircularBuffer::new(3);
+    assert_eq!(cb.push_overwrite(1), None);
+    assert_eq!(cb.push_overwrite(2), None);
+    assert_eq!(cb.len(), 2);
+    assert!(!cb.is_full());
+}
+
+#[test]
+fn test_push_overwrite_evicts_oldest() {
+    let mut cb = CircularBuffer::new(3);
+    cb.push(1).unwrap();
+    cb.push(2).unwrap();
+    cb.push(3).unwrap();
+    assert!(cb.is_full());
+
+    assert_eq!(cb.push_overwrite(4), Some(1));
+    assert!(cb.is_full());
+    assert_eq!(cb.len(), 3);
+
+    let collected: Vec<_> = cb.into_iter().collect();
+    assert_eq!(collected, vec![2, 3, 4]);
+}
+
+#[test]
+fn test_push_overwrite_as_sliding_window() {
+    let mut cb = CircularBuffer::new(3);
+    for i in 1..=6 {
+        cb.push_overwrite(i);
+    }
+
+    let collected: Vec<_> = cb.into_iter().collect();
+    assert_eq!(collected, vec![4, 5, 6]);
+}
+
+#[test]
+fn test_push_overwrite_zero_capacity() {
+    let mut cb: CircularBuffer<i32> = CircularBuffer::new(0);
+    assert_eq!(cb.push_overwrite(1), None);
+    assert!(cb.is_empty());
+}
+
+#[test]
+fn test_as_slices_no_wrap() {
+    let mut cb = CircularBuffer::new(5);
+    cb.push(1).unwrap();
+    cb.push(2).unwrap();
+    cb.push(3).unwrap();
+
+    let (first, second) = cb.as_slices();
+    assert_eq!(first, &[1, 2, 3]);
+    assert!(second.is_empty());
+}
+
+#[test]
+fn test_as_slices_after_wrap() {
+    let mut cb = CircularBuffer::new(5);
+    for i in 1..=5 {
+        cb.push(i).unwrap();
+    }
+    cb.pop().unwrap(); // Remove 1
+    cb.pop().unwrap(); // Remove 2
+    cb.push(6).unwrap();
+    cb.push(7).unwrap(); // Buffer is now [3,4,5,6,7], wrapped
+
+    let (first, second) = cb.as_slices();
+    let joined: Vec<_> = first.iter().chain(second.iter()).copied().collect();
+    assert_eq!(joined, vec![3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_as_mut_slices_allows_in_place_mutation() {
+    let mut cb = CircularBuffer::new(4);
+    for i in 1..=4 {
+        cb.push(i).unwrap();
+    }
+    cb.pop().unwrap(); // Remove 1
+    cb.pop().unwrap(); // Remove 2
+    cb.push(5).unwrap();
+    cb.push(6).unwrap(); // Buffer is now [3,4,5,6], wrapped
+
+    {
+        let (first, second) = cb.as_mut_slices();
+        for value in first.iter_mut().chain(second.iter_mut()) {
+            *value *= 10;
+        }
+    }
+
+    let collected: Vec<_> = cb.into_iter().collect();
+    assert_eq!(collected, vec![30, 40, 50, 60]);
+}
+
+#[test]
+fn test_as_slices_empty_buffer() {
+    let cb: CircularBuffer<i32> = CircularBuffer::new(3);
+    let (first, second) = cb.as_slices();
+    assert!(first.is_empty());
+    assert!(second.is_empty());
+}
+
+#[test]
+fn test_iter_does_not_consume_buffer() {
+    let mut cb = CircularBuffer::new(5);
+    cb.push(1).unwrap();
+    cb.push(2).unwrap();
+    cb.push(3).unwrap();
+
+    let collected: Vec<_> = cb.iter().copied().collect();
+    assert_eq!(collected, vec![1, 2, 3]);
+    assert_eq!(cb.len(), 3);
+}
+
+#[test]
+fn test_iter_after_wrap_around() {
+    let mut cb = CircularBuffer::new(5);
+    for i in 1..=5 {
+        cb.push(i).unwrap();
+    }
+    cb.pop().unwrap(); // Remove 1
+    cb.pop().unwrap(); // Remove 2
+    cb.push(6).unwrap();
+    cb.push(7).unwrap(); // Buffer is now [3,4,5,6,7], wrapped
+
+    let collected: Vec<_> = cb.iter().copied().collect();
+    assert_eq!(collected, vec![3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_iter_rev_walks_newest_to_oldest() {
+    let mut cb = CircularBuffer::new(4);
+    for i in 1..=4 {
+        cb.push(i).unwrap();
+    }
+
+    let collected: Vec<_> = cb.iter().rev().copied().collect();
+    assert_eq!(collected, vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn test_iter_mixing_front_and_back() {
+    let mut cb = CircularBuffer::new(5);
+    for i in 1..=5 {
+        cb.push(i).unwrap();
+    }
+
+    let mut iter = cb.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&5));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_iter_mut_allows_in_place_mutation() {
+    let mut cb = CircularBuffer::new(4);
+    for i in 1..=4 {
+        cb.push(i).unwrap();
+    }
+    cb.pop().unwrap(); // Remove 1
+    cb.push(5).unwrap(); // Buffer is now [2,3,4,5], wrapped
+
+    for value in cb.iter_mut() {
+        *value *= 10;
+    }
+
+    let collected: Vec<_> = cb.iter().copied().collect();
+    assert_eq!(collected, vec![20, 30, 40, 50]);
+}
+
+#[test]
+fn test_iter_mut_rev() {
+    let mut cb = CircularBuffer::new(3);
+    cb.push(1).unwrap();
+    cb.push(2).unwrap();
+    cb.push(3).unwrap();
+
+    let collected: Vec<_> = cb.iter_mut().rev().map(|v| *v).collect();
+    assert_eq!(collected, vec![3, 2, 1]);
+}
+
+#[test]
+fn test_push_front_and_pop_back() {
+    let mut cb = CircularBuffer::new(3);
+    cb.push_front(2).unwrap();
+    cb.push_front(1).unwrap();
+    cb.push(3).unwrap();
+
+    let collected: Vec<_> = cb.clone().into_iter().collect();
+    assert_eq!(collected, vec![1, 2, 3]);
+
+    assert_eq!(cb.pop_back(), Some(3));
+    assert_eq!(cb.pop_back(), Some(2));
+    assert_eq!(cb.pop_back(), Some(1));
+    assert_eq!(cb.pop_back(), None);
+}
+
+#[test]
+fn test_push_front_full_buffer() {
+    let mut cb = CircularBuffer::new(2);
+    assert_eq!(cb.push_front(1), Ok(()));
+    assert_eq!(cb.push_front(2), Ok(()));
+    assert_eq!(cb.push_front(3), Err(BufferError::BufferFull));
+}
+
+#[test]
+fn test_pop_back_empty_buffer() {
+    let mut cb: CircularBuffer<i32> = CircularBuffer::new(2);
+    assert_eq!(cb.pop_back(), None);
+}
+
+#[test]
+fn test_deque_operations_after_wrap() {
+    let mut cb = CircularBuffer::new(4);
+    cb.push(1).unwrap();
+    cb.push(2).unwrap();
+    cb.pop().unwrap(); // Remove 1, front moves to slot 1
+    cb.push_front(0).unwrap(); // front steps back to slot 0
+    cb.push(3).unwrap();
+    cb.push(4).unwrap(); // rear wraps around to slot 0
+
+    let collected: Vec<_> = cb.into_iter().collect();
+    assert_eq!(collected, vec![0, 2, 3, 4]);
+}
+
+#[test]
+fn test_index_and_index_mut() {
+    let mut cb = CircularBuffer::new(5);
+    for i in 1..=5 {
+        cb.push(i).unwrap();
+    }
+    cb.pop().unwrap(); // Remove 1
+    cb.pop().unwrap(); // Remove 2
+    cb.push(6).unwrap();
+    cb.push(7).unwrap(); // Buffer is now [3,4,5,6,7], wrapped
+
+    assert_eq!(cb[0], 3);
+    assert_eq!(cb[4], 7);
+
+    cb[0] = 30;
+    assert_eq!(cb[0], 30);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_bounds_panics() {
+    let cb: CircularBuffer<i32> = CircularBuffer::new(3);
+    let _ = cb[0];
+}
+
 #[test]
 fn test_repeated_push_pop() {
     let capacity = 3;
@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use discoid::spsc;
+use std::thread;
+
+#[test]
+fn push_then_pop_single_element() {
+    let (mut writer, mut reader) = spsc::bounded::<i32>(4);
+    assert_eq!(writer.push(10), Ok(()));
+    assert_eq!(reader.pop(), Some(10));
+    assert_eq!(reader.pop(), None);
+}
+
+#[test]
+fn push_fails_when_full() {
+    let (mut writer, _reader) = spsc::bounded::<i32>(2);
+    assert_eq!(writer.push(1), Ok(()));
+    assert_eq!(writer.push(2), Ok(()));
+    assert_eq!(writer.push(3), Err(3));
+}
+
+#[test]
+fn pop_fails_when_empty() {
+    let (_writer, mut reader) = spsc::bounded::<i32>(2);
+    assert_eq!(reader.pop(), None);
+}
+
+#[test]
+fn fifo_order_is_preserved_across_wrap() {
+    let (mut writer, mut reader) = spsc::bounded::<i32>(3);
+    for i in 1..=3 {
+        writer.push(i).unwrap();
+    }
+    assert_eq!(reader.pop(), Some(1));
+    assert_eq!(reader.pop(), Some(2));
+    writer.push(4).unwrap();
+    writer.push(5).unwrap();
+    assert_eq!(reader.pop(), Some(3));
+    assert_eq!(reader.pop(), Some(4));
+    assert_eq!(reader.pop(), Some(5));
+    assert_eq!(reader.pop(), None);
+}
+
+#[test]
+fn writer_and_reader_hand_off_across_threads() {
+    let (mut writer, mut reader) = spsc::bounded::<i32>(8);
+
+    let producer = thread::spawn(move || {
+        for i in 0..1000 {
+            while writer.push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    let consumer = thread::spawn(move || {
+        let mut received = Vec::with_capacity(1000);
+        while received.len() < 1000 {
+            if let Some(value) = reader.pop() {
+                received.push(value);
+            } else {
+                thread::yield_now();
+            }
+        }
+        received
+    });
+
+    producer.join().unwrap();
+    let received = consumer.join().unwrap();
+    assert_eq!(received, (0..1000).collect::<Vec<_>>());
+}
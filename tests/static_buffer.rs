@@ -0,0 +1,49 @@
+use discoid::{StaticBufferError, StaticCircularBuffer};
+
+#[test]
+fn push_and_pop_single_element() {
+    let mut cb: StaticCircularBuffer<i32, 3> = StaticCircularBuffer::new();
+    assert_eq!(cb.len(), 0);
+    assert!(cb.is_empty());
+
+    assert_eq!(cb.push(10), Ok(()));
+    assert_eq!(cb.len(), 1);
+    assert_eq!(cb.get(0), Some(&10));
+
+    assert_eq!(cb.pop(), Some(10));
+    assert!(cb.is_empty());
+}
+
+#[test]
+fn push_full_buffer_returns_error() {
+    let mut cb: StaticCircularBuffer<i32, 2> = StaticCircularBuffer::new();
+    assert_eq!(cb.push(1), Ok(()));
+    assert_eq!(cb.push(2), Ok(()));
+    assert!(cb.is_full());
+
+    assert_eq!(cb.push(3), Err(StaticBufferError::BufferFull));
+}
+
+#[test]
+fn wraps_around_capacity() {
+    let mut cb: StaticCircularBuffer<i32, 3> = StaticCircularBuffer::new();
+    for i in 1..=3 {
+        cb.push(i).unwrap();
+    }
+    assert_eq!(cb.pop(), Some(1));
+    assert_eq!(cb.pop(), Some(2));
+    cb.push(4).unwrap();
+    cb.push(5).unwrap();
+
+    assert_eq!(cb.get(0), Some(&3));
+    assert_eq!(cb.get(1), Some(&4));
+    assert_eq!(cb.get(2), Some(&5));
+}
+
+#[test]
+fn drop_releases_non_copy_elements() {
+    let mut cb: StaticCircularBuffer<String, 2> = StaticCircularBuffer::new();
+    cb.push(String::from("a")).unwrap();
+    cb.push(String::from("b")).unwrap();
+    drop(cb); // Must not leak or double-free the held `String`s.
+}